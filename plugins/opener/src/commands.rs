@@ -18,6 +18,7 @@ pub async fn open_url<R: Runtime>(
     global_scope: GlobalScope<crate::scope::Entry>,
     path: String,
     with: Option<Program>,
+    args: Option<Vec<String>>,
 ) -> crate::Result<()> {
     let scope = Scope::new(
         &app,
@@ -33,8 +34,14 @@ pub async fn open_url<R: Runtime>(
             .collect(),
     );
 
+    if let Some(Program::Custom { path: program, .. }) = &with {
+        if !scope.is_program_allowed(program) {
+            return Err(Error::ForbiddenPath(program.display().to_string()));
+        }
+    }
+
     if scope.is_url_allowed(&path) {
-        crate::open_url(path, with)
+        crate::open_url(path, with, args)
     } else {
         Err(Error::ForbiddenUrl(path))
     }
@@ -47,6 +54,7 @@ pub async fn open_path<R: Runtime>(
     global_scope: GlobalScope<crate::scope::Entry>,
     path: String,
     with: Option<Program>,
+    args: Option<Vec<String>>,
 ) -> crate::Result<()> {
     let scope = Scope::new(
         &app,
@@ -62,14 +70,126 @@ pub async fn open_path<R: Runtime>(
             .collect(),
     );
 
+    if let Some(Program::Custom { path: program, .. }) = &with {
+        if !scope.is_program_allowed(program) {
+            return Err(Error::ForbiddenPath(program.display().to_string()));
+        }
+    }
+
     if scope.is_path_allowed(Path::new(&path))? {
-        crate::open_path(path, with)
+        crate::open_path(path, with, args)
     } else {
         Err(Error::ForbiddenPath(path))
     }
 }
 
 #[tauri::command]
-pub async fn reveal_item_in_dir(path: PathBuf) -> crate::Result<()> {
-    crate::reveal_item_in_dir(path)
+pub async fn reveal_item_in_dir<R: Runtime>(
+    app: AppHandle<R>,
+    command_scope: CommandScope<crate::scope::Entry>,
+    global_scope: GlobalScope<crate::scope::Entry>,
+    path: PathBuf,
+) -> crate::Result<()> {
+    let scope = Scope::new(
+        &app,
+        command_scope
+            .allows()
+            .iter()
+            .chain(global_scope.allows())
+            .collect(),
+        command_scope
+            .denies()
+            .iter()
+            .chain(global_scope.denies())
+            .collect(),
+    );
+
+    if !scope.is_path_allowed(&path)? {
+        return Err(Error::ForbiddenPath(path.to_string_lossy().to_string()));
+    }
+
+    reveal_item(&path)
+}
+
+/// Reveals `path` in the platform's file manager, with the item itself selected (as opposed to
+/// merely opening its parent directory).
+#[cfg(target_os = "macos")]
+fn reveal_item(path: &Path) -> crate::Result<()> {
+    // `open -R` asks Finder to reveal *and select* the item.
+    std::process::Command::new("open")
+        .arg("-R")
+        .arg(path)
+        .spawn()
+        .map(|_| ())
+        .map_err(Into::into)
+}
+
+/// Reveals `path` in the platform's file manager, with the item itself selected (as opposed to
+/// merely opening its parent directory).
+#[cfg(target_os = "windows")]
+fn reveal_item(path: &Path) -> crate::Result<()> {
+    // `/select,` tells Explorer to open the parent folder with `path` highlighted.
+    let mut arg = std::ffi::OsString::from("/select,");
+    arg.push(path.as_os_str());
+    std::process::Command::new("explorer")
+        .arg(arg)
+        .spawn()
+        .map(|_| ())
+        .map_err(Into::into)
+}
+
+/// Reveals `path` in the platform's file manager, with the item itself selected (as opposed to
+/// merely opening its parent directory).
+#[cfg(target_os = "linux")]
+fn reveal_item(path: &Path) -> crate::Result<()> {
+    // Ask the user's file manager to highlight the item via the freedesktop
+    // `FileManager1.ShowItems` D-Bus method; this is the only portable way to get the item
+    // itself selected. Fall back to `xdg-open`ing the parent directory (without selection) if
+    // no file manager is registered on the session bus.
+    if show_items_via_dbus(path).is_ok() {
+        return Ok(());
+    }
+
+    let parent = path.parent().unwrap_or(path);
+    ::open::that_detached(parent).map_err(Into::into)
+}
+
+#[cfg(target_os = "linux")]
+fn show_items_via_dbus(path: &Path) -> Result<(), ()> {
+    let uri = format!("file://{}", percent_encode_path(path));
+    std::process::Command::new("dbus-send")
+        .args([
+            "--session",
+            "--dest=org.freedesktop.FileManager1",
+            "--type=method_call",
+            "/org/freedesktop/FileManager1",
+            "org.freedesktop.FileManager1.ShowItems",
+            &format!("array:string:{uri}"),
+            "string:",
+        ])
+        .status()
+        .map_err(|_| ())
+        .and_then(|status| status.success().then_some(()).ok_or(()))
+}
+
+/// Percent-encodes `path` for use in a `file://` URI, so spaces and other reserved characters
+/// (extremely common in real-world paths) don't produce an invalid URI that `ShowItems` rejects.
+#[cfg(target_os = "linux")]
+fn percent_encode_path(path: &Path) -> String {
+    let mut encoded = String::new();
+    for byte in path.to_string_lossy().as_bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                encoded.push(*byte as char)
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+fn reveal_item(path: &Path) -> crate::Result<()> {
+    let parent = path.parent().unwrap_or(path);
+    ::open::that_detached(parent).map_err(Into::into)
 }
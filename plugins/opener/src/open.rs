@@ -6,7 +6,12 @@
 
 use serde::{Deserialize, Deserializer};
 
-use std::{ffi::OsStr, fmt::Display, path::Path, str::FromStr};
+use std::{
+    ffi::OsStr,
+    fmt::Display,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
 /// Program to use on the [`open()`] call.
 #[derive(Debug)]
@@ -33,27 +38,42 @@ pub enum Program {
     Chromium,
     /// Use the `Safari` program.
     Safari,
+    /// Launch an arbitrary program with the given arguments.
+    ///
+    /// Unlike the other variants, this is not one of a fixed set of known binaries, so the
+    /// opener scope must explicitly allow-list `path` before a command may use it — see
+    /// [`crate::scope::Scope`].
+    Custom {
+        /// Path to the executable to launch.
+        path: PathBuf,
+        /// Arguments passed to the executable, before the path or URL being opened.
+        args: Vec<String>,
+    },
 }
 
 impl Display for Program {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Self::Open => "open",
-                Self::Start => "start",
-                Self::XdgOpen => "xdg-open",
-                Self::Gio => "gio",
-                Self::GnomeOpen => "gnome-open",
-                Self::KdeOpen => "kde-open",
-                Self::WslView => "wslview",
-                Self::Firefox => "firefox",
-                Self::Chrome => "chrome",
-                Self::Chromium => "chromium",
-                Self::Safari => "safari",
-            }
-        )
+        match self {
+            Self::Custom { path, .. } => write!(f, "{}", path.display()),
+            _ => write!(
+                f,
+                "{}",
+                match self {
+                    Self::Open => "open",
+                    Self::Start => "start",
+                    Self::XdgOpen => "xdg-open",
+                    Self::Gio => "gio",
+                    Self::GnomeOpen => "gnome-open",
+                    Self::KdeOpen => "kde-open",
+                    Self::WslView => "wslview",
+                    Self::Firefox => "firefox",
+                    Self::Chrome => "chrome",
+                    Self::Chromium => "chromium",
+                    Self::Safari => "safari",
+                    Self::Custom { .. } => unreachable!(),
+                }
+            ),
+        }
     }
 }
 
@@ -79,19 +99,40 @@ impl FromStr for Program {
     }
 }
 
+/// On-the-wire representation of [`Program`]: either the name of one of the well-known
+/// programs, or an object describing a [`Program::Custom`] launch.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ProgramRepr {
+    Named(String),
+    Custom {
+        path: PathBuf,
+        #[serde(default)]
+        args: Vec<String>,
+    },
+}
+
 impl<'de> Deserialize<'de> for Program {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let s = String::deserialize(deserializer)?;
-        Program::from_str(&s).map_err(|e| serde::de::Error::custom(e.to_string()))
+        match ProgramRepr::deserialize(deserializer)? {
+            ProgramRepr::Named(s) => {
+                Program::from_str(&s).map_err(|e| serde::de::Error::custom(e.to_string()))
+            }
+            ProgramRepr::Custom { path, args } => Ok(Program::Custom { path, args }),
+        }
     }
 }
 
 impl Program {
-    pub(crate) fn name(self) -> &'static str {
-        match self {
+    /// The executable name or path to spawn for this program.
+    ///
+    /// Returns `None` for [`Program::Custom`]: its `path` is used together with its `args`
+    /// instead of this shortcut, since it also needs its argument vector threaded through.
+    pub(crate) fn name(&self) -> Option<&'static str> {
+        Some(match self {
             Self::Open => "open",
             Self::Start => "start",
             Self::XdgOpen => "xdg-open",
@@ -119,48 +160,921 @@ impl Program {
             Self::Safari => "Safari",
             #[cfg(not(target_os = "macos"))]
             Self::Safari => "safari",
-        }
+
+            Self::Custom { .. } => return None,
+        })
     }
 }
 
-pub(crate) fn open<P: AsRef<OsStr>>(path: P, with: Option<Program>) -> crate::Result<()> {
-    match with.map(Program::name) {
-        Some(program) => ::open::with_detached(path, program),
-        None => ::open::that_detached(path),
+pub(crate) fn open<P: AsRef<OsStr>>(
+    path: P,
+    with: Option<Program>,
+    args: Option<Vec<String>>,
+    is_url: bool,
+) -> crate::Result<()> {
+    match with {
+        Some(Program::Custom {
+            path: program,
+            args: custom_args,
+        }) => {
+            let mut all_args = custom_args;
+            all_args.extend(args.unwrap_or_default());
+            spawn_with_args(path, &program, &all_args)
+        }
+        Some(program) => match args {
+            Some(args) if !args.is_empty() => {
+                spawn_with_args(path, Path::new(program.name().expect("checked above")), &args)
+            }
+            _ => open_named_program(path.as_ref(), program.name().expect("checked above")),
+        },
+        None => {
+            #[cfg(any(
+                target_os = "linux",
+                target_os = "freebsd",
+                target_os = "dragonfly",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            ))]
+            if is_url && try_browser_env(path.as_ref()) {
+                return Ok(());
+            }
+            #[cfg(not(any(
+                target_os = "linux",
+                target_os = "freebsd",
+                target_os = "dragonfly",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            )))]
+            let _ = is_url;
+
+            open_system_default(path.as_ref())
+        }
     }
     .map_err(Into::into)
 }
 
+/// Launches the named well-known program on `path`.
+///
+/// On Linux this is implemented with our own [`spawn_detached`] rather than the `open` crate's
+/// helper, so that a bundled app (AppImage/Flatpak/Snap) can pass a [sanitized
+/// environment](sandbox_env) to the spawned process without mutating its own.
+#[cfg(target_os = "linux")]
+fn open_named_program(path: &OsStr, program: &str) -> std::io::Result<()> {
+    spawn_detached(OsStr::new(program), [path])
+}
+
+#[cfg(not(target_os = "linux"))]
+fn open_named_program(path: &OsStr, program: &str) -> std::io::Result<()> {
+    ::open::with_detached(path, program)
+}
+
+/// Opens `path` with the system default handler.
+///
+/// On Linux this reimplements the `xdg-open` → `gio open` → `gnome-open` → `kde-open` fallback
+/// chain ourselves (rather than delegating to the `open` crate) so each attempt can carry a
+/// [sanitized environment](sandbox_env) scoped to that one child process.
+#[cfg(target_os = "linux")]
+fn open_system_default(path: &OsStr) -> std::io::Result<()> {
+    const OPENERS: &[(&str, &[&str])] = &[
+        ("xdg-open", &[]),
+        ("gio", &["open"]),
+        ("gnome-open", &[]),
+        ("kde-open", &[]),
+    ];
+
+    for (program, leading_args) in OPENERS {
+        let mut args: Vec<&OsStr> = leading_args.iter().map(OsStr::new).collect();
+        args.push(path);
+        if spawn_detached(OsStr::new(program), args).is_ok() {
+            return Ok(());
+        }
+    }
+
+    Err(std::io::Error::new(
+        std::io::ErrorKind::NotFound,
+        "no system opener (xdg-open, gio, gnome-open, kde-open) was found",
+    ))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn open_system_default(path: &OsStr) -> std::io::Result<()> {
+    ::open::that_detached(path)
+}
+
+/// Tries each entry of the user's `$BROWSER` environment variable in order, the way
+/// `webbrowser`-style libraries do, before the caller falls back to the `xdg-open` →
+/// `gio open` → `gnome-open` → `kde-open` chain.
+///
+/// `$BROWSER` is a `:`-separated list of commands; `%s` in an entry is replaced with the URL,
+/// otherwise the URL is appended as the last argument. Returns `true` as soon as one entry
+/// spawns successfully.
+#[cfg(any(
+    target_os = "linux",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+fn try_browser_env(url: &OsStr) -> bool {
+    let Some(browser_var) = std::env::var_os("BROWSER").and_then(|v| v.into_string().ok()) else {
+        return false;
+    };
+    let url_str = url.to_string_lossy();
+
+    for entry in browser_var.split(':') {
+        if entry.is_empty() {
+            continue;
+        }
+
+        // There's no shell involved here (same as the `open` crate's helpers), so the entry is
+        // tokenized on whitespace ourselves rather than handed to the launcher as one literal
+        // argv entry.
+        let mut tokens = entry.split_whitespace();
+        let Some(program) = tokens.next() else {
+            continue;
+        };
+        let rest: Vec<String> = tokens.map(str::to_string).collect();
+        let has_placeholder = rest.iter().any(|arg| arg.contains("%s"));
+
+        let succeeded = if has_placeholder {
+            let args: Vec<String> = rest
+                .iter()
+                .map(|arg| arg.replace("%s", &url_str))
+                .collect();
+            spawn_detached(OsStr::new(program), args.iter().map(String::as_str)).is_ok()
+        } else {
+            spawn_with_args(url, Path::new(program), &rest).is_ok()
+        };
+
+        if succeeded {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Spawns `program` detached with `args`, with no further arguments appended.
+fn spawn_detached<I, S>(program: &OsStr, args: I) -> std::io::Result<()>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+{
+    use std::process::{Command, Stdio};
+
+    let mut command = Command::new(program);
+    command
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+
+    #[cfg(target_os = "linux")]
+    apply_sandbox_env(&mut command);
+
+    command.spawn().map(|_| ())
+}
+
+/// Spawns `program` detached with `args` followed by `path` as its final argument.
+///
+/// Used instead of the `open` crate's helpers whenever extra arguments need to reach the
+/// program, since `open::with_detached` only accepts a bare program name.
+fn spawn_with_args<P: AsRef<OsStr>>(path: P, program: &Path, args: &[String]) -> std::io::Result<()> {
+    use std::process::{Command, Stdio};
+
+    let mut command = Command::new(program);
+    command
+        .args(args)
+        .arg(path.as_ref())
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+
+    #[cfg(target_os = "linux")]
+    apply_sandbox_env(&mut command);
+
+    command.spawn().map(|_| ())
+}
+
+/// Applies [`sandbox_env`]'s PATH-var overrides to `command` alone, leaving this process's own
+/// environment untouched. A no-op outside a bundled sandbox, since `sanitized_overrides` returns
+/// an empty list in that case.
+#[cfg(target_os = "linux")]
+fn apply_sandbox_env(command: &mut std::process::Command) {
+    for (var, value) in sandbox_env::sanitized_overrides() {
+        match value {
+            Some(value) => {
+                command.env(var, value);
+            }
+            None => {
+                command.env_remove(var);
+            }
+        }
+    }
+}
+
 /// Opens URL with the program specified in `with`, or system default if `None`.
 ///
+/// `args` are passed to `with` before the URL; they're ignored when `with` is `None`.
+///
 /// # Examples
 ///
 /// ```rust,no_run
 /// tauri::Builder::default()
 ///   .setup(|app| {
 ///     // open the given URL on the system default browser
-///     tauri_plugin_opener::open_url("https://github.com/tauri-apps/tauri", None)?;
+///     tauri_plugin_opener::open_url("https://github.com/tauri-apps/tauri", None, None)?;
 ///     Ok(())
 ///   });
 /// ```
-pub fn open_url<P: AsRef<str>>(url: P, with: Option<Program>) -> crate::Result<()> {
+pub fn open_url<P: AsRef<str>>(
+    url: P,
+    with: Option<Program>,
+    args: Option<Vec<String>>,
+) -> crate::Result<()> {
     let url = url.as_ref();
-    open(url, with)
+    open(url, with, args, true)
 }
 
 /// Opens path with the program specified in `with`, or system default if `None`.
 ///
+/// `args` are passed to `with` before the path; they're ignored when `with` is `None`.
+///
 /// # Examples
 ///
 /// ```rust,no_run
 /// tauri::Builder::default()
 ///   .setup(|app| {
 ///     // open the given URL on the system default browser
-///     tauri_plugin_opener::open_path("/path/to/file", None)?;
+///     tauri_plugin_opener::open_path("/path/to/file", None, None)?;
 ///     Ok(())
 ///   });
 /// ```
-pub fn open_path<P: AsRef<Path>>(path: P, with: Option<Program>) -> crate::Result<()> {
+pub fn open_path<P: AsRef<Path>>(
+    path: P,
+    with: Option<Program>,
+    args: Option<Vec<String>>,
+) -> crate::Result<()> {
     let path = path.as_ref();
-    open(path, with)
+    open(path, with, args, false)
+}
+
+/// An application that is registered on the system as being able to open a given file or URL.
+///
+/// Returned by [`get_opener_apps`] so a frontend can build an "Open With…" menu.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppInfo {
+    /// The application's display name, e.g. `Google Chrome`.
+    pub name: String,
+    /// A platform-specific identifier for the application.
+    ///
+    /// This is the bundle identifier on macOS, the `ProgId` on Windows, and the desktop file id
+    /// (e.g. `org.mozilla.firefox.desktop`) on Linux. Pass it back to [`open_path_with_app`] to
+    /// launch this application.
+    pub id: String,
+    /// The application's icon, in a platform-native encoding (e.g. PNG), if one could be read.
+    ///
+    /// Known limitation, tracked as a follow-up: icon extraction isn't implemented on any
+    /// platform yet, so this is currently always `None`. Treat `None` as "not available", not
+    /// as "this application has no icon".
+    pub icon: Option<Vec<u8>>,
+}
+
+/// Returns the applications installed on the system that are capable of opening `path_or_url`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// tauri::Builder::default()
+///   .setup(|app| {
+///     let apps = tauri_plugin_opener::get_opener_apps("/path/to/file.pdf")?;
+///     Ok(())
+///   });
+/// ```
+pub fn get_opener_apps<P: AsRef<OsStr>>(path_or_url: P) -> crate::Result<Vec<AppInfo>> {
+    get_opener_apps_impl(path_or_url.as_ref())
+}
+
+/// Opens `path` with the application identified by `app_id`, as returned by [`get_opener_apps`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// tauri::Builder::default()
+///   .setup(|app| {
+///     let apps = tauri_plugin_opener::get_opener_apps("/path/to/file.pdf")?;
+///     if let Some(app) = apps.first() {
+///       tauri_plugin_opener::open_path_with_app("/path/to/file.pdf", &app.id)?;
+///     }
+///     Ok(())
+///   });
+/// ```
+pub fn open_path_with_app<P: AsRef<Path>>(path: P, app_id: &str) -> crate::Result<()> {
+    open_path_with_app_impl(path.as_ref(), app_id)
+}
+
+#[cfg(target_os = "macos")]
+mod opener_apps {
+    use super::AppInfo;
+    use std::{ffi::OsStr, path::Path};
+
+    // `LSCopyApplicationURLsForURL` enumerates every app the Launch Services database considers
+    // capable of opening the URL. It's available since macOS 12; older systems fall back to
+    // asking Launch Services for the UTI's role handlers instead. Both return a `CFArray` of
+    // `CFURLRef`s (toll-free bridged to `NSURL`), not a `Vec`, so we walk it with the classic
+    // `CFArrayGetCount`/`CFArrayGetValueAtIndex` pair rather than assuming an `IntoIterator` impl.
+    pub(super) fn get_opener_apps_impl(path_or_url: &OsStr) -> crate::Result<Vec<AppInfo>> {
+        use objc2_core_services::{kLSRolesAll, LSCopyApplicationURLsForURL};
+
+        let url = super::to_ns_url(path_or_url)?;
+
+        let array = if objc2_foundation::NSProcessInfo::processInfo()
+            .isOperatingSystemAtLeastVersion(objc2_foundation::NSOperatingSystemVersion {
+                major_version: 12,
+                minor_version: 0,
+                patch_version: 0,
+            })
+        {
+            unsafe { LSCopyApplicationURLsForURL(&url, kLSRolesAll) }
+        } else {
+            legacy_handlers_for_uti(&url)
+        };
+
+        Ok(cf_array_to_app_infos(array))
+    }
+
+    pub(super) fn open_path_with_app_impl(path: &Path, app_id: &str) -> crate::Result<()> {
+        // `open -a` resolves its argument as an application name or path; it has no notion of a
+        // `"bundle:"` prefix. Launching by bundle identifier is `open -b <id> <path>`, which the
+        // `open` crate doesn't expose, so we invoke it directly.
+        std::process::Command::new("open")
+            .args(["-b", app_id])
+            .arg(path)
+            .spawn()
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+
+    fn legacy_handlers_for_uti(
+        url: &objc2_foundation::NSURL,
+    ) -> Option<objc2_core_foundation::CFRetained<objc2_core_foundation::CFArray>> {
+        // macOS < 12: resolve the file's UTI and ask Launch Services for every app whose
+        // `CFBundleDocumentTypes` claims it, via the (deprecated but still functional)
+        // `LSCopyApplicationURLsForURL` replacement `LSCopyAllRoleHandlersForContentType`.
+        use objc2_core_services::{kLSRolesAll, LSCopyAllRoleHandlersForContentType};
+
+        let uti = super::uti_for_url(url)?;
+        unsafe { LSCopyAllRoleHandlersForContentType(&uti, kLSRolesAll) }
+    }
+
+    /// Walks a `CFArray` of `CFURLRef`s (as returned by the Launch Services APIs above) and
+    /// resolves each one to an [`AppInfo`].
+    fn cf_array_to_app_infos(
+        array: Option<objc2_core_foundation::CFRetained<objc2_core_foundation::CFArray>>,
+    ) -> Vec<AppInfo> {
+        use objc2_core_foundation::{CFArrayGetCount, CFArrayGetValueAtIndex};
+
+        let Some(array) = array else {
+            return Vec::new();
+        };
+
+        let count = unsafe { CFArrayGetCount(&array) };
+        let mut apps = Vec::with_capacity(count.max(0) as usize);
+
+        for index in 0..count {
+            let raw_url = unsafe { CFArrayGetValueAtIndex(&array, index) };
+            if raw_url.is_null() {
+                continue;
+            }
+            // `CFURLRef` and `NSURL *` are toll-free bridged: the pointee has the same layout,
+            // so reinterpreting the pointer is sound as long as we only call Foundation methods
+            // that are part of that bridge (as `app_info_from_bundle_url` does).
+            let app_url = unsafe { &*(raw_url as *const objc2_foundation::NSURL) };
+            if let Some(app) = app_info_from_bundle_url(app_url) {
+                apps.push(app);
+            }
+        }
+
+        apps
+    }
+
+    fn app_info_from_bundle_url(app_url: &objc2_foundation::NSURL) -> Option<AppInfo> {
+        use objc2_foundation::NSBundle;
+
+        let bundle = unsafe { NSBundle::bundleWithURL(app_url) }?;
+        let id = unsafe { bundle.bundleIdentifier() }?.to_string();
+        let name = unsafe { bundle.localizedInfoDictionary() }
+            .and_then(|dict| dict.get("CFBundleName").cloned())
+            .or_else(|| unsafe { bundle.infoDictionary() }.and_then(|d| d.get("CFBundleName").cloned()))
+            .map(|v| v.to_string())
+            .unwrap_or_else(|| id.clone());
+        let icon = super::read_app_icon(app_url);
+
+        Some(AppInfo { name, id, icon })
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod opener_apps {
+    use super::AppInfo;
+    use std::{ffi::OsStr, path::Path};
+    use windows_registry::{CLASSES_ROOT, CURRENT_USER, LOCAL_MACHINE};
+
+    // Windows keeps two registrations worth reading: the per-extension `OpenWithProgids` list
+    // under `HKCR\<ext>`, and the system-wide `Applications` hive, which also covers apps that
+    // only registered themselves generically (not tied to a specific extension).
+    pub(super) fn get_opener_apps_impl(path_or_url: &OsStr) -> crate::Result<Vec<AppInfo>> {
+        let mut apps = Vec::new();
+        let mut seen = std::collections::HashSet::new();
+
+        if let Some(ext) = Path::new(path_or_url)
+            .extension()
+            .and_then(|e| e.to_str())
+        {
+            for progid in open_with_progids(&format!(".{ext}")) {
+                if seen.insert(progid.clone()) {
+                    if let Some(app) = app_info_from_progid(&progid) {
+                        apps.push(app);
+                    }
+                }
+            }
+        }
+
+        for progid in applications_hive_entries() {
+            if seen.insert(progid.clone()) {
+                if let Some(app) = app_info_from_progid(&progid) {
+                    apps.push(app);
+                }
+            }
+        }
+
+        Ok(apps)
+    }
+
+    pub(super) fn open_path_with_app_impl(path: &Path, app_id: &str) -> crate::Result<()> {
+        let command = command_line_for_progid(app_id).ok_or_else(|| {
+            crate::Error::Io(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("no registered application with id `{app_id}`"),
+            ))
+        })?;
+        spawn_command_line(&command, path)
+    }
+
+    fn open_with_progids(ext: &str) -> Vec<String> {
+        // `HKEY_CLASSES_ROOT` is itself a merged view of `HKCU\Software\Classes` over
+        // `HKLM\Software\Classes`, so reading `HKCR\<ext>\OpenWithProgids` through it returns
+        // every app registered as capable of handling the extension — not just the ones the
+        // user already picked via Explorer's "Open With" UI, which is all the
+        // `Explorer\FileExts` key under `HKCU` reflects.
+        //
+        // `Key::values()` returns a `Result` wrapping the iterator (opening the enumeration can
+        // itself fail), so the key has to stay in scope alongside it rather than being dropped
+        // at the end of a `.map()` closure.
+        let Ok(key) = CLASSES_ROOT.open(format!("{ext}\\OpenWithProgids")) else {
+            return Vec::new();
+        };
+        let Ok(values) = key.values() else {
+            return Vec::new();
+        };
+
+        values.map(|(name, _)| name).collect()
+    }
+
+    fn applications_hive_entries() -> Vec<String> {
+        let Ok(key) = LOCAL_MACHINE.open("Software\\Classes\\Applications") else {
+            return Vec::new();
+        };
+        let Ok(keys) = key.keys() else {
+            return Vec::new();
+        };
+
+        // These live at `Software\Classes\Applications\<name>\...`, not `Software\Classes\
+        // <name>\...`, so the `Applications\` segment has to travel with the id for
+        // `app_info_from_progid`/`command_line_for_progid` to resolve it later.
+        keys.filter_map(Result::ok)
+            .map(|name| format!("Applications\\{name}"))
+            .collect()
+    }
+
+    fn app_info_from_progid(progid: &str) -> Option<AppInfo> {
+        let root = CURRENT_USER
+            .open(format!("Software\\Classes\\{progid}"))
+            .or_else(|_| LOCAL_MACHINE.open(format!("Software\\Classes\\{progid}")))
+            .ok()?;
+
+        let name = root
+            .get_string("FriendlyTypeName")
+            .or_else(|_| root.get_string(""))
+            .unwrap_or_else(|_| progid.to_string());
+
+        Some(AppInfo {
+            name,
+            id: progid.to_string(),
+            // TODO: extract the icon from the `DefaultIcon` key (an "<path>,<index>" pair into
+            // an .exe/.dll/.ico resource) and decode it to bytes; left unimplemented for now, so
+            // `icon` is always `None` on Windows.
+            icon: None,
+        })
+    }
+
+    fn command_line_for_progid(progid: &str) -> Option<String> {
+        CURRENT_USER
+            .open(format!("Software\\Classes\\{progid}\\shell\\open\\command"))
+            .or_else(|_| LOCAL_MACHINE.open(format!("Software\\Classes\\{progid}\\shell\\open\\command")))
+            .ok()?
+            .get_string("")
+            .ok()
+    }
+
+    fn spawn_command_line(command: &str, path: &Path) -> crate::Result<()> {
+        // `that_detached` would spawn the *default-handler opener*, not the program named in
+        // this command line, so it's tokenized and spawned directly instead.
+        let command = command.replace("%1", &path.to_string_lossy());
+        let mut argv = split_command_line(&command);
+        if argv.is_empty() {
+            return Err(crate::Error::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "registered open command is empty",
+            )));
+        }
+        let program = argv.remove(0);
+        super::spawn_detached(std::ffi::OsStr::new(&program), argv).map_err(Into::into)
+    }
+
+    /// Splits a Windows shell command line into its program and arguments, honoring
+    /// double-quoted segments (as produced by `shell\open\command` values, e.g.
+    /// `"C:\Program Files\App\app.exe" "%1"`).
+    fn split_command_line(command: &str) -> Vec<String> {
+        let mut args = Vec::new();
+        let mut current = String::new();
+        let mut in_quotes = false;
+
+        for c in command.chars() {
+            match c {
+                '"' => in_quotes = !in_quotes,
+                c if c.is_whitespace() && !in_quotes => {
+                    if !current.is_empty() {
+                        args.push(std::mem::take(&mut current));
+                    }
+                }
+                c => current.push(c),
+            }
+        }
+        if !current.is_empty() {
+            args.push(current);
+        }
+
+        args
+    }
+}
+
+#[cfg(any(
+    target_os = "linux",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+mod opener_apps {
+    use super::AppInfo;
+    use std::{collections::HashMap, ffi::OsStr, path::Path, process::Command};
+
+    // Every desktop file registered for the file's MIME type under `$XDG_DATA_DIRS/applications`
+    // is a candidate "Open With…" entry; `xdg-mime` already knows how to resolve a path or URL to
+    // a MIME type, so we shell out to it rather than re-implementing sniffing.
+    pub(super) fn get_opener_apps_impl(path_or_url: &OsStr) -> crate::Result<Vec<AppInfo>> {
+        let Some(mime) = query_mime_type(path_or_url) else {
+            return Ok(Vec::new());
+        };
+
+        let mut apps = Vec::new();
+        for dir in application_dirs() {
+            let Ok(entries) = std::fs::read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.extension().and_then(|e| e.to_str()) != Some("desktop") {
+                    continue;
+                }
+                if let Some(app) = parse_desktop_entry(&path, &mime) {
+                    apps.push(app);
+                }
+            }
+        }
+
+        Ok(apps)
+    }
+
+    pub(super) fn open_path_with_app_impl(path: &Path, app_id: &str) -> crate::Result<()> {
+        let exec = application_dirs()
+            .iter()
+            .find_map(|dir| exec_line(&dir.join(app_id)))
+            .ok_or_else(|| {
+                crate::Error::Io(std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    format!("no desktop entry found for `{app_id}`"),
+                ))
+            })?;
+
+        // `that_detached` would spawn the *default-handler opener* (`xdg-open`/`gio open`/…)
+        // with its argument treated as a path/URL, not as a program name plus argv — so the
+        // `Exec=` line has to stay tokenized and be spawned directly instead.
+        let mut argv = expand_exec(&exec, path);
+        if argv.is_empty() {
+            return Err(crate::Error::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("desktop entry for `{app_id}` has an empty Exec line"),
+            )));
+        }
+        let program = argv.remove(0);
+        super::spawn_detached(OsStr::new(&program), argv).map_err(Into::into)
+    }
+
+    fn query_mime_type(path_or_url: &OsStr) -> Option<String> {
+        let output = Command::new("xdg-mime")
+            .arg("query")
+            .arg("filetype")
+            .arg(path_or_url)
+            .output()
+            .ok()?;
+        let mime = String::from_utf8(output.stdout).ok()?.trim().to_string();
+        (!mime.is_empty()).then_some(mime)
+    }
+
+    fn application_dirs() -> Vec<std::path::PathBuf> {
+        std::env::var_os("XDG_DATA_DIRS")
+            .unwrap_or_else(|| "/usr/local/share:/usr/share".into())
+            .to_string_lossy()
+            .split(':')
+            .map(|dir| Path::new(dir).join("applications"))
+            .collect()
+    }
+
+    fn parse_desktop_entry(path: &Path, mime: &str) -> Option<AppInfo> {
+        let entries = read_desktop_entry(path)?;
+        let mime_types = entries.get("MimeType")?;
+        if !mime_types.split(';').any(|m| m == mime) {
+            return None;
+        }
+
+        Some(AppInfo {
+            name: entries.get("Name")?.clone(),
+            id: path.file_name()?.to_string_lossy().into_owned(),
+            icon: entries.get("Icon").and_then(|icon| read_icon_file(icon)),
+        })
+    }
+
+    fn exec_line(path: &Path) -> Option<String> {
+        read_desktop_entry(path)?.get("Exec").cloned()
+    }
+
+    fn read_desktop_entry(path: &Path) -> Option<HashMap<String, String>> {
+        let content = std::fs::read_to_string(path).ok()?;
+        let mut in_desktop_entry = false;
+        let mut entries = HashMap::new();
+
+        for line in content.lines() {
+            let line = line.trim();
+            if line.starts_with('[') {
+                in_desktop_entry = line == "[Desktop Entry]";
+                continue;
+            }
+            if !in_desktop_entry {
+                continue;
+            }
+            if let Some((key, value)) = line.split_once('=') {
+                entries.insert(key.trim().to_string(), value.trim().to_string());
+            }
+        }
+
+        Some(entries)
+    }
+
+    fn read_icon_file(_icon_name: &str) -> Option<Vec<u8>> {
+        // TODO: resolve the icon name against the user's icon theme (hicolor, pixmaps, etc.)
+        // and read the resulting file; unimplemented for now, so `icon` is always `None` on
+        // Linux. Callers must treat `None` as "no icon available", not "this app has no icon".
+        None
+    }
+
+    /// Expands a desktop entry's `Exec=` line into its argv, substituting `%f`/`%F`/`%u`/`%U`
+    /// with `path` and appending `path` as a final argument when none of those placeholders are
+    /// present. Returned as a tokenized `Vec<String>` (not a joined command line) so the caller
+    /// can spawn it directly with `Command::new(program).args(rest)`.
+    fn expand_exec(exec: &str, path: &Path) -> Vec<String> {
+        let path = path.to_string_lossy();
+        let mut substituted = false;
+
+        let mut argv: Vec<String> = exec
+            .split_whitespace()
+            .map(|token| {
+                if token.contains("%f") || token.contains("%F") || token.contains("%u") || token.contains("%U")
+                {
+                    substituted = true;
+                    token
+                        .replace("%f", &path)
+                        .replace("%F", &path)
+                        .replace("%u", &path)
+                        .replace("%U", &path)
+                } else {
+                    token.replace("%%", "%")
+                }
+            })
+            .collect();
+
+        if !substituted {
+            argv.push(path.into_owned());
+        }
+
+        argv
+    }
+}
+
+#[cfg(not(any(
+    target_os = "macos",
+    target_os = "windows",
+    target_os = "linux",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd"
+)))]
+mod opener_apps {
+    use super::AppInfo;
+    use std::{ffi::OsStr, path::Path};
+
+    pub(super) fn get_opener_apps_impl(_path_or_url: &OsStr) -> crate::Result<Vec<AppInfo>> {
+        Ok(Vec::new())
+    }
+
+    pub(super) fn open_path_with_app_impl(path: &Path, app_id: &str) -> crate::Result<()> {
+        ::open::with_detached(path, app_id).map_err(Into::into)
+    }
+}
+
+use opener_apps::{get_opener_apps_impl, open_path_with_app_impl};
+
+#[cfg(target_os = "macos")]
+fn to_ns_url(path_or_url: &OsStr) -> crate::Result<objc2::rc::Retained<objc2_foundation::NSURL>> {
+    use objc2_foundation::{NSString, NSURL};
+
+    let s = path_or_url.to_string_lossy();
+    let ns_string = NSString::from_str(&s);
+    let url = if s.contains("://") {
+        unsafe { NSURL::URLWithString(&ns_string) }
+    } else {
+        Some(unsafe { NSURL::fileURLWithPath(&ns_string) })
+    };
+
+    url.ok_or_else(|| {
+        crate::Error::Io(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "failed to build an NSURL",
+        ))
+    })
+}
+
+#[cfg(target_os = "macos")]
+fn uti_for_url(
+    url: &objc2_foundation::NSURL,
+) -> Option<objc2::rc::Retained<objc2_foundation::NSString>> {
+    unsafe { url.getResourceValue_forKey_error(objc2_foundation::NSURLTypeIdentifierKey) }
+}
+
+#[cfg(target_os = "macos")]
+fn read_app_icon(app_url: &objc2_foundation::NSURL) -> Option<Vec<u8>> {
+    // TODO: read `NSWorkspace`'s `icon(forFile:)` and re-encode it (e.g. to PNG); unimplemented
+    // for now, so `icon` is always `None` on macOS. Callers must treat `None` as "no icon
+    // available", not "this app has no icon".
+    let _ = app_url;
+    None
+}
+
+/// Utilities for sanitizing the environment before spawning an external application from a
+/// sandboxed bundle (AppImage, Flatpak, or Snap).
+///
+/// Bundlers commonly rewrite library and data search paths (`LD_LIBRARY_PATH`,
+/// `GST_PLUGIN_PATH`, `XDG_DATA_DIRS`, `GIO_MODULE_DIR`, …) so the bundled app finds its own
+/// copies of shared libraries. Those rewritten values leak into any child process [`open()`]
+/// spawns, which frequently crashes external GTK/GStreamer apps that expect the host's paths.
+#[cfg(target_os = "linux")]
+pub mod sandbox_env {
+    use std::{
+        collections::HashSet,
+        env,
+        ffi::{OsStr, OsString},
+    };
+
+    /// Environment variables bundlers commonly rewrite to point into the bundle.
+    const BUNDLE_PATH_VARS: &[&str] = &[
+        "LD_LIBRARY_PATH",
+        "GST_PLUGIN_PATH",
+        "GST_PLUGIN_SYSTEM_PATH",
+        "XDG_DATA_DIRS",
+        "GIO_MODULE_DIR",
+        "GTK_PATH",
+        "GTK_EXE_PREFIX",
+        "GDK_PIXBUF_MODULE_FILE",
+    ];
+
+    /// Returns `true` when running inside a Flatpak sandbox.
+    pub fn is_flatpak() -> bool {
+        std::path::Path::new("/.flatpak-info").exists()
+    }
+
+    /// Returns `true` when running inside a Snap sandbox.
+    pub fn is_snap() -> bool {
+        env::var_os("SNAP").is_some()
+    }
+
+    /// Returns `true` when running from an AppImage.
+    pub fn is_appimage() -> bool {
+        env::var_os("APPIMAGE").is_some()
+    }
+
+    fn is_bundled() -> bool {
+        is_flatpak() || is_snap() || is_appimage()
+    }
+
+    /// Computes the `PATH`-like environment variable overrides a spawned child should get to
+    /// undo a bundler's (AppImage/Flatpak/Snap) rewrites, restoring each to its host value so
+    /// GTK/GStreamer apps launched from inside the bundle don't pick up its library/plugin
+    /// search paths.
+    ///
+    /// Returns a list of `(var, new_value)` pairs to apply to the *child's* environment only —
+    /// via [`std::process::Command::env`]/`env_remove` — rather than touching this process's
+    /// environment, which would affect everything else still running in it. `new_value` of
+    /// `None` means the variable should be unset for the child.
+    ///
+    /// Bundle-aware loaders conventionally stash the pre-bundling value of a variable under
+    /// `<VAR>_ORIG` before overwriting it; we prefer that host value when it's present. Otherwise
+    /// we fall back to stripping bundle-internal entries out of the current value.
+    pub(super) fn sanitized_overrides() -> Vec<(&'static str, Option<OsString>)> {
+        if !is_bundled() {
+            return Vec::new();
+        }
+
+        let mut overrides = Vec::new();
+
+        for &var in BUNDLE_PATH_VARS {
+            let Some(current) = env::var_os(var) else {
+                continue;
+            };
+
+            match env::var_os(format!("{var}_ORIG")) {
+                Some(host) if !host.is_empty() => overrides.push((var, Some(host))),
+                _ => match strip_bundle_entries(&current) {
+                    Some(cleaned) if !cleaned.is_empty() => {
+                        overrides.push((var, Some(OsString::from(cleaned))));
+                    }
+                    Some(_) => overrides.push((var, None)),
+                    None => {}
+                },
+            }
+        }
+
+        overrides
+    }
+
+    /// Rebuilds a colon-separated search path, dropping bundle-internal entries and
+    /// de-duplicating the rest. On duplicates, the lower-priority (later) entry wins, since a
+    /// bundle-prepended duplicate of a host entry should not keep the host entry from moving
+    /// to its original, lower-priority position.
+    fn strip_bundle_entries(value: &OsStr) -> Option<String> {
+        let value = value.to_str()?;
+        let bundle_root = bundle_root();
+
+        let mut seen = HashSet::new();
+        let mut deduped = Vec::new();
+        for entry in value.split(':').rev() {
+            if entry.is_empty() {
+                continue;
+            }
+            if bundle_root
+                .as_deref()
+                .is_some_and(|root| entry.starts_with(root))
+            {
+                continue;
+            }
+            if seen.insert(entry) {
+                deduped.push(entry);
+            }
+        }
+        deduped.reverse();
+
+        Some(deduped.join(":"))
+    }
+
+    fn bundle_root() -> Option<String> {
+        if is_flatpak() {
+            Some("/app".to_string())
+        } else if let Some(snap) = env::var_os("SNAP") {
+            Some(snap.to_string_lossy().into_owned())
+        } else {
+            env::var_os("APPDIR").map(|dir| dir.to_string_lossy().into_owned())
+        }
+    }
 }
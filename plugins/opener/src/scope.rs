@@ -0,0 +1,190 @@
+// Copyright 2019-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Scope for the opener commands.
+
+use serde::Deserialize;
+use std::path::Path;
+use tauri::{AppHandle, Runtime};
+
+/// A scope entry, as configured in a capability file.
+///
+/// Each field is optional and independently gates one of the opener commands: `path` for
+/// [`crate::open_path`]/`reveal_item_in_dir`, `url` for [`crate::open_url`], and `program` for
+/// launching a [`crate::open::Program::Custom`].
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Entry {
+    /// A path or glob pattern this entry allows or denies.
+    #[serde(default)]
+    pub path: Option<String>,
+    /// A URL or glob pattern this entry allows or denies.
+    #[serde(default)]
+    pub url: Option<String>,
+    /// The absolute path of a program this entry allows or denies launching via
+    /// [`crate::open::Program::Custom`].
+    ///
+    /// Custom programs have no default allow-list: an entry must name this field explicitly
+    /// (an exact path, no wildcards), otherwise a frontend could turn the opener into an arbitrary-exec
+    /// primitive by supplying any binary it likes.
+    #[serde(default)]
+    pub program: Option<std::path::PathBuf>,
+}
+
+/// Resolved scope for a single opener command invocation, combining the command-level and
+/// global-level allow/deny entries.
+pub struct Scope {
+    allowed: Vec<Entry>,
+    denied: Vec<Entry>,
+}
+
+impl Scope {
+    pub(crate) fn new<R: Runtime>(
+        _app: &AppHandle<R>,
+        allowed: Vec<&Entry>,
+        denied: Vec<&Entry>,
+    ) -> Self {
+        Self {
+            allowed: allowed.into_iter().cloned().collect(),
+            denied: denied.into_iter().cloned().collect(),
+        }
+    }
+
+    /// Returns whether `url` is allowed by this scope.
+    pub(crate) fn is_url_allowed(&self, url: &str) -> bool {
+        if self
+            .denied
+            .iter()
+            .any(|entry| matches_pattern(entry.url.as_deref(), url))
+        {
+            return false;
+        }
+
+        self.allowed
+            .iter()
+            .any(|entry| matches_pattern(entry.url.as_deref(), url))
+    }
+
+    /// Returns whether `path` is allowed by this scope.
+    pub(crate) fn is_path_allowed(&self, path: &Path) -> crate::Result<bool> {
+        let path = path.to_string_lossy();
+
+        if self
+            .denied
+            .iter()
+            .any(|entry| matches_pattern(entry.path.as_deref(), &path))
+        {
+            return Ok(false);
+        }
+
+        Ok(self
+            .allowed
+            .iter()
+            .any(|entry| matches_pattern(entry.path.as_deref(), &path)))
+    }
+
+    /// Returns whether `program` has been explicitly allow-listed for use with
+    /// [`crate::open::Program::Custom`].
+    pub(crate) fn is_program_allowed(&self, program: &Path) -> bool {
+        if self.denied.iter().any(|entry| program_matches(entry, program)) {
+            return false;
+        }
+
+        self.allowed.iter().any(|entry| program_matches(entry, program))
+    }
+}
+
+fn program_matches(entry: &Entry, program: &Path) -> bool {
+    entry
+        .program
+        .as_deref()
+        .is_some_and(|allowed| allowed == program)
+}
+
+/// Matches `value` against `pattern` in full (i.e. anchored at both ends), where `pattern` may
+/// contain `*` as a wildcard matching any number of characters (including zero).
+///
+/// This is the standard two-pointer wildcard-matching algorithm (as used for shell `*` globs
+/// with no other special characters), not a segment-by-segment `str::find` walk: finding each
+/// literal segment's *leftmost* occurrence produces false negatives whenever that segment (in
+/// particular the trailing one) also occurs earlier in `value` — e.g. a naive walk fails to
+/// match `"a*bc"` against `"abcbc"`, even though `"abcbc"` plainly ends in `"bc"`. Backtracking
+/// to the most recent `*` on a mismatch (instead of committing to the first segment match found)
+/// avoids that class of bug.
+fn matches_pattern(pattern: Option<&str>, value: &str) -> bool {
+    let Some(pattern) = pattern else {
+        return false;
+    };
+
+    let pattern = pattern.as_bytes();
+    let value = value.as_bytes();
+
+    let (mut p, mut v) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None; // (position in pattern after '*', position in value to resume from)
+
+    while v < value.len() {
+        if p < pattern.len() && pattern[p] == b'*' {
+            backtrack = Some((p + 1, v));
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == value[v] {
+            p += 1;
+            v += 1;
+        } else if let Some((bp, bv)) = backtrack {
+            p = bp;
+            v = bv + 1;
+            backtrack = Some((bp, v));
+        } else {
+            return false;
+        }
+    }
+
+    while pattern.get(p) == Some(&b'*') {
+        p += 1;
+    }
+
+    p == pattern.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::matches_pattern;
+
+    #[test]
+    fn exact_match() {
+        assert!(matches_pattern(Some("/home/user/file.txt"), "/home/user/file.txt"));
+        assert!(!matches_pattern(Some("/home/user/file.txt"), "/home/user/other.txt"));
+    }
+
+    #[test]
+    fn wildcard_prefix_and_suffix() {
+        assert!(matches_pattern(Some("/secret/*.key"), "/secret/a.key"));
+        assert!(matches_pattern(Some("https://*.example.com/*"), "https://api.example.com/v1"));
+    }
+
+    #[test]
+    fn repeated_trailing_segment_is_not_a_false_negative() {
+        // A leftmost-`str::find` walk stops at the first "bc" (index 1) and is left with the
+        // unmatched remainder "bc" of the value and no pattern to match it — so it wrongly
+        // reports no match even though the string does end in "bc".
+        assert!(matches_pattern(Some("a*bc"), "abcbc"));
+    }
+
+    #[test]
+    fn repeated_segment_deny_pattern_is_not_bypassable() {
+        // Mirrors the scenario from the review: a deny entry for `/secret/*.key` must still
+        // catch a value whose tail contains another copy of the literal suffix.
+        assert!(matches_pattern(Some("/secret/*.key"), "/secret/a.key.key"));
+    }
+
+    #[test]
+    fn no_match_when_literal_suffix_is_not_at_end() {
+        assert!(!matches_pattern(Some("/secret/*.key"), "/secret/a.key.txt"));
+        assert!(!matches_pattern(Some("a*bc"), "abcx"));
+    }
+
+    #[test]
+    fn missing_pattern_never_matches() {
+        assert!(!matches_pattern(None, "anything"));
+    }
+}